@@ -1,7 +1,8 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc, Timelike, Datelike, Weekday};
 use clap::{Args, Parser, Subcommand};
-use git2::{Repository, RemoteCallbacks};
+use git2::{BranchType, Mailmap, Patch, Repository, RemoteCallbacks, Revwalk};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -27,48 +28,125 @@ enum Commands {
     Activity(ActivityArgs),
     Files(FilesArgs),
     All(AllArgs),
+    Hours(HoursArgs),
 }
 
 #[derive(Args)]
 struct ContributorsArgs {
     #[arg(short, long, default_value = ".")]
-    path: PathBuf,
+    path: Vec<PathBuf>,
+    #[arg(long, value_name = "DIR", num_args = 1.., help = "Additional repositories to aggregate alongside --path")]
+    repos: Vec<PathBuf>,
     #[arg(short, long)]
     json: bool,
     #[arg(short, long, help = "Clone and analyze remote repository from URL")]
     url: Option<String>,
+    #[arg(long, value_name = "YYYY-MM-DD", help = "Only include commits on or after this date (default: one year ago)")]
+    since: Option<String>,
+    #[arg(long, value_name = "YYYY-MM-DD", help = "Only include commits on or before this date")]
+    until: Option<String>,
+    #[arg(long, value_name = "NAME", num_args = 1.., help = "Analyze specific branches instead of just HEAD (repeatable)")]
+    branches: Vec<String>,
+    #[arg(long, help = "Analyze every local branch")]
+    all_branches: bool,
+    #[arg(long, value_name = "PATH", help = "Use an external mailmap file instead of the repo's .mailmap")]
+    mailmap: Option<PathBuf>,
+    #[arg(long, default_value_t = 0, help = "Number of worker threads for commit processing (default: available parallelism)")]
+    jobs: usize,
 }
 
 #[derive(Args)]
 struct ActivityArgs {
     #[arg(short, long, default_value = ".")]
-    path: PathBuf,
+    path: Vec<PathBuf>,
+    #[arg(long, value_name = "DIR", num_args = 1.., help = "Additional repositories to aggregate alongside --path")]
+    repos: Vec<PathBuf>,
     #[arg(short, long)]
     json: bool,
     #[arg(short, long, help = "Clone and analyze remote repository from URL")]
     url: Option<String>,
+    #[arg(long, value_name = "YYYY-MM-DD", help = "Only include commits on or after this date (default: one year ago)")]
+    since: Option<String>,
+    #[arg(long, value_name = "YYYY-MM-DD", help = "Only include commits on or before this date")]
+    until: Option<String>,
+    #[arg(long, value_name = "NAME", num_args = 1.., help = "Analyze specific branches instead of just HEAD (repeatable)")]
+    branches: Vec<String>,
+    #[arg(long, help = "Analyze every local branch")]
+    all_branches: bool,
+    #[arg(long, help = "Render a GitHub-style contribution calendar heatmap")]
+    heatmap: bool,
+    #[arg(long, value_enum, default_value_t = HeatmapColor::Green, help = "Color ramp for the heatmap")]
+    color: HeatmapColor,
+    #[arg(long, default_value_t = 0, help = "Number of worker threads for commit processing (default: available parallelism)")]
+    jobs: usize,
 }
 
 #[derive(Args)]
 struct FilesArgs {
     #[arg(short, long, default_value = ".")]
-    path: PathBuf,
+    path: Vec<PathBuf>,
+    #[arg(long, value_name = "DIR", num_args = 1.., help = "Additional repositories to aggregate alongside --path")]
+    repos: Vec<PathBuf>,
     #[arg(short, long)]
     json: bool,
     #[arg(short, long, help = "Clone and analyze remote repository from URL")]
     url: Option<String>,
+    #[arg(long, value_name = "YYYY-MM-DD", help = "Only include commits on or after this date (default: one year ago)")]
+    since: Option<String>,
+    #[arg(long, value_name = "YYYY-MM-DD", help = "Only include commits on or before this date")]
+    until: Option<String>,
+    #[arg(long, value_name = "NAME", num_args = 1.., help = "Analyze specific branches instead of just HEAD (repeatable)")]
+    branches: Vec<String>,
+    #[arg(long, help = "Analyze every local branch")]
+    all_branches: bool,
+    #[arg(long, value_enum, default_value_t = FileSortBy::Commits, help = "Rank files by commit frequency or total line churn")]
+    sort: FileSortBy,
+    #[arg(long, default_value_t = 0, help = "Number of worker threads for diff processing (default: available parallelism)")]
+    jobs: usize,
 }
 
 #[derive(Args)]
 struct AllArgs {
+    #[arg(short, long, default_value = ".")]
+    path: Vec<PathBuf>,
+    #[arg(long, value_name = "DIR", num_args = 1.., help = "Additional repositories to aggregate alongside --path")]
+    repos: Vec<PathBuf>,
+    #[arg(short, long)]
+    json: bool,
+    #[arg(short, long, help = "Clone and analyze remote repository from URL")]
+    url: Option<String>,
+    #[arg(long, value_name = "YYYY-MM-DD", help = "Only include commits on or after this date (default: one year ago)")]
+    since: Option<String>,
+    #[arg(long, value_name = "YYYY-MM-DD", help = "Only include commits on or before this date")]
+    until: Option<String>,
+    #[arg(long, value_name = "NAME", num_args = 1.., help = "Analyze specific branches instead of just HEAD (repeatable)")]
+    branches: Vec<String>,
+    #[arg(long, help = "Analyze every local branch")]
+    all_branches: bool,
+    #[arg(long, default_value_t = 0, help = "Number of worker threads for commit processing (default: available parallelism)")]
+    jobs: usize,
+}
+
+#[derive(Args)]
+struct HoursArgs {
     #[arg(short, long, default_value = ".")]
     path: PathBuf,
     #[arg(short, long)]
     json: bool,
     #[arg(short, long, help = "Clone and analyze remote repository from URL")]
     url: Option<String>,
+    #[arg(long, value_name = "PATH", help = "Use an external mailmap file instead of the repo's .mailmap")]
+    mailmap: Option<PathBuf>,
 }
 
+// Tuning constants for the git-hours-style session estimator: commits less
+// than MAX_COMMIT_DIFFERENCE_MINUTES apart are treated as the same coding
+// session, and every session (including the very first commit) gets a flat
+// FIRST_COMMIT_ADDITION_MINUTES to account for un-timed work leading up to it.
+const MAX_COMMIT_DIFFERENCE_MINUTES: i64 = 120;
+const FIRST_COMMIT_ADDITION_MINUTES: i64 = 120;
+const HOURS_PER_WORKDAY: f64 = 8.0;
+
 #[derive(Serialize, Deserialize, Debug)]
 struct ContributorStats {
     name: String,
@@ -78,6 +156,7 @@ struct ContributorStats {
     last_commit: DateTime<Utc>,
     work_pattern: WorkPattern,
     hourly_commits: HashMap<u8, u32>,
+    repos: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -92,8 +171,44 @@ struct WorkPattern {
 #[derive(Serialize, Deserialize, Debug)]
 struct FileStats {
     path: String,
+    repo: String,
     commits: u32,
     last_modified: DateTime<Utc>,
+    lines_added: u32,
+    lines_removed: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ContributorHours {
+    name: String,
+    email: String,
+    commits: u32,
+    hours: f64,
+    estimated_days: f64,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum HeatmapColor {
+    Green,
+    Red,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum FileSortBy {
+    Commits,
+    Churn,
+}
+
+/// Bundles the settings shared by every collector: the date window, branch
+/// selection, mailmap override, and worker-thread count. Passed by reference
+/// instead of as a long, ever-growing positional parameter list.
+struct RunOptions<'a> {
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    branches: &'a [String],
+    all_branches: bool,
+    mailmap_path: &'a Option<PathBuf>,
+    jobs: usize,
 }
 
 fn main() -> Result<()> {
@@ -101,26 +216,134 @@ fn main() -> Result<()> {
 
     match cli.command {
         Commands::Contributors(args) => {
-            let repo_path = get_repo_path(&args.path, &args.url)?;
-            analyze_contributors(&repo_path, args.json)?
+            let repo_paths = resolve_repo_paths(&args.path, &args.repos, &args.url)?;
+            let (since, until) = resolve_date_range(&args.since, &args.until)?;
+            let opts = RunOptions { since, until, branches: &args.branches, all_branches: args.all_branches, mailmap_path: &args.mailmap, jobs: args.jobs };
+            analyze_contributors(&repo_paths, args.json, &opts)?
         },
         Commands::Activity(args) => {
-            let repo_path = get_repo_path(&args.path, &args.url)?;
-            analyze_activity(&repo_path, args.json)?
+            let repo_paths = resolve_repo_paths(&args.path, &args.repos, &args.url)?;
+            let (since, until) = resolve_date_range(&args.since, &args.until)?;
+            let opts = RunOptions { since, until, branches: &args.branches, all_branches: args.all_branches, mailmap_path: &None, jobs: args.jobs };
+            analyze_activity(&repo_paths, args.json, &opts, args.heatmap, args.color)?
         },
         Commands::Files(args) => {
-            let repo_path = get_repo_path(&args.path, &args.url)?;
-            analyze_files(&repo_path, args.json)?
+            let repo_paths = resolve_repo_paths(&args.path, &args.repos, &args.url)?;
+            let (since, until) = resolve_date_range(&args.since, &args.until)?;
+            let opts = RunOptions { since, until, branches: &args.branches, all_branches: args.all_branches, mailmap_path: &None, jobs: args.jobs };
+            analyze_files(&repo_paths, args.json, &opts, args.sort)?
         },
         Commands::All(args) => {
+            let repo_paths = resolve_repo_paths(&args.path, &args.repos, &args.url)?;
+            let (since, until) = resolve_date_range(&args.since, &args.until)?;
+            let opts = RunOptions { since, until, branches: &args.branches, all_branches: args.all_branches, mailmap_path: &None, jobs: args.jobs };
+            analyze_all(&repo_paths, args.json, &opts)?
+        },
+        Commands::Hours(args) => {
             let repo_path = get_repo_path(&args.path, &args.url)?;
-            analyze_all(&repo_path, args.json)?
+            analyze_hours(&repo_path, args.json, &args.mailmap)?
         },
     }
 
     Ok(())
 }
 
+fn parse_date_bound(date_str: &str) -> Result<DateTime<Utc>> {
+    let naive = chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
+        .map_err(|e| anyhow::anyhow!("Invalid date '{}', expected YYYY-MM-DD: {}", date_str, e))?;
+    Ok(naive.and_hms_opt(0, 0, 0).unwrap().and_utc())
+}
+
+fn resolve_date_range(since: &Option<String>, until: &Option<String>) -> Result<(DateTime<Utc>, DateTime<Utc>)> {
+    let since_date = match since {
+        Some(s) => parse_date_bound(s)?,
+        None => Utc::now() - chrono::Duration::days(365),
+    };
+    // The until bound covers the whole named day, up to its last second.
+    let until_date = match until {
+        Some(u) => parse_date_bound(u)? + chrono::Duration::days(1) - chrono::Duration::seconds(1),
+        None => Utc::now(),
+    };
+    Ok((since_date, until_date))
+}
+
+fn push_refs_for_analysis(repo: &Repository, revwalk: &mut Revwalk, branches: &[String], all_branches: bool) -> Result<()> {
+    if all_branches {
+        for branch in repo.branches(Some(BranchType::Local))? {
+            let (branch, _) = branch?;
+            if let Some(target) = branch.get().target() {
+                revwalk.push(target)?;
+            }
+        }
+    } else if !branches.is_empty() {
+        for name in branches {
+            let branch = repo.find_branch(name, BranchType::Local)?;
+            let target = branch.get().target()
+                .ok_or_else(|| anyhow::anyhow!("Branch '{}' has no target commit", name))?;
+            revwalk.push(target)?;
+        }
+    } else {
+        revwalk.push_head()?;
+    }
+
+    Ok(())
+}
+
+fn load_mailmap(repo: &Repository, override_path: &Option<PathBuf>) -> Result<Mailmap> {
+    match override_path {
+        Some(path) => {
+            let buffer = std::fs::read_to_string(path)?;
+            Ok(Mailmap::from_buffer(&buffer)?)
+        },
+        None => Ok(repo.mailmap()?),
+    }
+}
+
+fn resolve_repo_paths(paths: &[PathBuf], repos: &[PathBuf], url: &Option<String>) -> Result<Vec<PathBuf>> {
+    if let Some(repo_url) = url {
+        return Ok(vec![get_repo_path(&PathBuf::from("."), &Some(repo_url.clone()))?]);
+    }
+
+    let mut all_paths: Vec<PathBuf> = paths.to_vec();
+    all_paths.extend(repos.iter().cloned());
+
+    if all_paths.is_empty() {
+        all_paths.push(PathBuf::from("."));
+    }
+
+    Ok(all_paths)
+}
+
+fn repo_display_name(path: &PathBuf) -> String {
+    path.canonicalize()
+        .ok()
+        .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+/// Builds a dedicated rayon thread pool for OID-level parallelism. `jobs == 0`
+/// means "use all available parallelism", matching the `--jobs` default.
+fn build_thread_pool(jobs: usize) -> Result<rayon::ThreadPool> {
+    let num_threads = if jobs > 0 {
+        jobs
+    } else {
+        std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+    };
+
+    Ok(rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()?)
+}
+
+/// Splits `len` items into roughly `num_chunks` contiguous chunks so each
+/// worker thread gets its own slice of OIDs to walk.
+fn chunk_size(len: usize, num_chunks: usize) -> usize {
+    if num_chunks == 0 || len == 0 {
+        return len.max(1);
+    }
+    len.div_ceil(num_chunks).max(1)
+}
+
 fn get_repo_path(local_path: &PathBuf, url: &Option<String>) -> Result<PathBuf> {
     match url {
         Some(repo_url) => {
@@ -182,10 +405,9 @@ fn get_repo_path(local_path: &PathBuf, url: &Option<String>) -> Result<PathBuf>
     }
 }
 
-fn analyze_contributors(repo_path: &PathBuf, json_output: bool) -> Result<()> {
-    let repo = Repository::open(repo_path)?;
-    let contributors = collect_contributor_data(&repo)?;
-    
+fn analyze_contributors(repo_paths: &[PathBuf], json_output: bool, opts: &RunOptions) -> Result<()> {
+    let contributors = collect_contributor_data(repo_paths, opts)?;
+
     if json_output {
         println!("{}", serde_json::to_string_pretty(&contributors)?);
     } else {
@@ -221,55 +443,19 @@ fn analyze_contributors(repo_path: &PathBuf, json_output: bool) -> Result<()> {
     Ok(())
 }
 
-fn collect_contributor_data(repo: &Repository) -> Result<Vec<ContributorStats>> {
-    let mut contributors: HashMap<String, (ContributorStats, Vec<DateTime<Utc>>)> = HashMap::new();
-    
-    let mut revwalk = repo.revwalk()?;
-    revwalk.push_head()?;
-    
-    for oid in revwalk {
-        let oid = oid?;
-        let commit = repo.find_commit(oid)?;
-        let author = commit.author();
-        let author_name = author.name().unwrap_or("Unknown").to_string();
-        let author_email = author.email().unwrap_or("unknown@example.com").to_string();
-        let commit_time = DateTime::from_timestamp(author.when().seconds(), 0)
-            .unwrap_or_default()
-            .with_timezone(&Utc);
-        
-        let key = format!("{} <{}>", author_name, author_email);
-        
-        contributors.entry(key)
-            .and_modify(|(stats, times)| {
-                stats.commits += 1;
-                if commit_time < stats.first_commit {
-                    stats.first_commit = commit_time;
-                }
-                if commit_time > stats.last_commit {
-                    stats.last_commit = commit_time;
-                }
-                times.push(commit_time);
-            })
-            .or_insert((
-                ContributorStats {
-                    name: author_name,
-                    email: author_email,
-                    commits: 1,
-                    first_commit: commit_time,
-                    last_commit: commit_time,
-                    work_pattern: WorkPattern {
-                        pattern_type: "unknown".to_string(),
-                        day_commits: 0,
-                        night_commits: 0,
-                        weekend_commits: 0,
-                        confidence: 0.0,
-                    },
-                    hourly_commits: HashMap::new(),
-                },
-                vec![commit_time],
-            ));
+/// Per-author contributor aggregate: the running stats plus the raw commit
+/// timestamps needed to derive a work pattern once all repos are folded in.
+type ContributorAccum = HashMap<String, (ContributorStats, Vec<DateTime<Utc>>)>;
+
+fn collect_contributor_data(repo_paths: &[PathBuf], opts: &RunOptions) -> Result<Vec<ContributorStats>> {
+    let mut contributors: ContributorAccum = HashMap::new();
+    let pool = build_thread_pool(opts.jobs)?;
+
+    for repo_path in repo_paths {
+        let repo_name = repo_display_name(repo_path);
+        accumulate_contributor_data(repo_path, &repo_name, opts, &pool, &mut contributors)?;
     }
-    
+
     let mut sorted_contributors: Vec<ContributorStats> = contributors
         .into_iter()
         .map(|(_, (mut stats, times))| {
@@ -278,12 +464,130 @@ fn collect_contributor_data(repo: &Repository) -> Result<Vec<ContributorStats>>
             stats
         })
         .collect();
-    
+
     sorted_contributors.sort_by(|a, b| b.commits.cmp(&a.commits));
-    
+
     Ok(sorted_contributors)
 }
 
+/// Merges a chunk-local contributor aggregate into the shared map, summing
+/// commit counts and widening the first/last-commit and repo-set bounds.
+fn merge_contributor_partial(contributors: &mut ContributorAccum, key: String, incoming: (ContributorStats, Vec<DateTime<Utc>>)) {
+    contributors.entry(key)
+        .and_modify(|(stats, times)| {
+            stats.commits += incoming.0.commits;
+            if incoming.0.first_commit < stats.first_commit {
+                stats.first_commit = incoming.0.first_commit;
+            }
+            if incoming.0.last_commit > stats.last_commit {
+                stats.last_commit = incoming.0.last_commit;
+            }
+            for repo in &incoming.0.repos {
+                if !stats.repos.iter().any(|r| r == repo) {
+                    stats.repos.push(repo.clone());
+                }
+            }
+            times.extend(incoming.1.iter().cloned());
+        })
+        .or_insert(incoming);
+}
+
+type AuthorTimes = HashMap<String, (String, String, Vec<DateTime<Utc>>)>;
+
+/// Walks `oids` and groups commit timestamps by mailmap-resolved author
+/// identity, keyed by `"name <email>"`. Shared by the contributors and hours
+/// collectors so both attribute commits from the same person under different
+/// identities to a single merged entry. `date_range`, when set, restricts
+/// results to `since..=until`.
+fn collect_author_times(repo: &Repository, mailmap: &Mailmap, oids: &[git2::Oid], date_range: Option<(DateTime<Utc>, DateTime<Utc>)>) -> Result<AuthorTimes> {
+    let mut authors: AuthorTimes = HashMap::new();
+
+    for &oid in oids {
+        let commit = repo.find_commit(oid)?;
+        let author = mailmap.resolve_signature(&commit.author())?;
+        let author_name = author.name().unwrap_or("Unknown").to_string();
+        let author_email = author.email().unwrap_or("unknown@example.com").to_string();
+        let commit_time = DateTime::from_timestamp(commit.author().when().seconds(), 0)
+            .unwrap_or_default()
+            .with_timezone(&Utc);
+
+        if let Some((since, until)) = date_range {
+            if commit_time < since || commit_time > until {
+                continue;
+            }
+        }
+
+        let key = format!("{} <{}>", author_name, author_email);
+
+        authors.entry(key)
+            .and_modify(|(_, _, times)| times.push(commit_time))
+            .or_insert((author_name, author_email, vec![commit_time]));
+    }
+
+    Ok(authors)
+}
+
+/// Walks `repo_path`'s history and folds per-author commit stats into
+/// `contributors`. Commit OIDs are collected up front and fanned out across
+/// `pool`'s worker threads, each opening its own `Repository` handle (git2's
+/// `Repository` is not `Sync`), with per-thread partials merged afterward.
+fn accumulate_contributor_data(repo_path: &PathBuf, repo_name: &str, opts: &RunOptions, pool: &rayon::ThreadPool, contributors: &mut ContributorAccum) -> Result<()> {
+    let repo = Repository::open(repo_path)?;
+    let mut revwalk = repo.revwalk()?;
+    push_refs_for_analysis(&repo, &mut revwalk, opts.branches, opts.all_branches)?;
+    let oids = revwalk.collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let chunk_len = chunk_size(oids.len(), pool.current_num_threads());
+    let (since, until, mailmap_path) = (opts.since, opts.until, opts.mailmap_path);
+
+    let partials: Vec<ContributorAccum> = pool.install(|| {
+        oids.par_chunks(chunk_len)
+            .map(|chunk| -> Result<ContributorAccum> {
+                let repo = Repository::open(repo_path)?;
+                let mailmap = load_mailmap(&repo, mailmap_path)?;
+                let chunk_authors = collect_author_times(&repo, &mailmap, chunk, Some((since, until)))?;
+
+                let mut local: ContributorAccum = HashMap::new();
+                for (key, (author_name, author_email, times)) in chunk_authors {
+                    let commits = times.len() as u32;
+                    let first_commit = *times.iter().min().unwrap();
+                    let last_commit = *times.iter().max().unwrap();
+
+                    local.insert(key, (
+                        ContributorStats {
+                            name: author_name,
+                            email: author_email,
+                            commits,
+                            first_commit,
+                            last_commit,
+                            work_pattern: WorkPattern {
+                                pattern_type: "unknown".to_string(),
+                                day_commits: 0,
+                                night_commits: 0,
+                                weekend_commits: 0,
+                                confidence: 0.0,
+                            },
+                            hourly_commits: HashMap::new(),
+                            repos: vec![repo_name.to_string()],
+                        },
+                        times,
+                    ));
+                }
+
+                Ok(local)
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    for partial in partials {
+        for (key, incoming) in partial {
+            merge_contributor_partial(contributors, key, incoming);
+        }
+    }
+
+    Ok(())
+}
+
 fn calculate_work_pattern(commit_times: &[DateTime<Utc>]) -> WorkPattern {
     let mut day_commits = 0;
     let mut night_commits = 0;
@@ -340,27 +644,32 @@ fn calculate_hourly_distribution(commit_times: &[DateTime<Utc>]) -> HashMap<u8,
     hourly_commits
 }
 
-fn analyze_activity(repo_path: &PathBuf, json_output: bool) -> Result<()> {
-    let repo = Repository::open(repo_path)?;
-    let (monthly_commits, hourly_commits) = collect_activity_data(&repo)?;
+fn analyze_activity(repo_paths: &[PathBuf], json_output: bool, opts: &RunOptions, heatmap: bool, color: HeatmapColor) -> Result<()> {
+    let (monthly_commits, hourly_commits, daily_commits) = collect_activity_data(repo_paths, opts)?;
 
     if json_output {
         let activity = serde_json::json!({
             "monthly_commits": monthly_commits,
-            "hourly_commits": hourly_commits
+            "hourly_commits": hourly_commits,
+            "daily_commits": daily_commits
         });
         println!("{}", serde_json::to_string_pretty(&activity)?);
     } else {
         println!("📈 Commit Activity by Month:");
         let mut sorted_months: Vec<_> = monthly_commits.iter().collect();
         sorted_months.sort_by(|a, b| a.0.cmp(b.0));
-        
+
         for (month, count) in sorted_months {
             println!("{}: {} commits", month, count);
         }
 
         println!("\n📊 Commit Activity by Hour:");
         display_hourly_chart(&hourly_commits);
+
+        if heatmap {
+            println!("\n🗓️  Contribution Calendar:");
+            display_commit_heatmap(&daily_commits, opts.since, opts.until, color);
+        }
     }
 
     Ok(())
@@ -401,55 +710,181 @@ fn display_hourly_chart(hourly_commits: &HashMap<u8, u32>) {
     }
     
     println!("\nLegend: 🌙 Night  🌅 Morning  ☀️ Day  🌆 Evening");
-    println!("Scale: Each █ represents {} commit(s)", 
-        if *max_commits > max_bar_width { 
-            (*max_commits as f32 / max_bar_width as f32).ceil() as u32 
-        } else { 
-            1 
+    println!("Scale: Each █ represents {} commit(s)",
+        if *max_commits > max_bar_width {
+            (*max_commits as f32 / max_bar_width as f32).ceil() as u32
+        } else {
+            1
         }
     );
 }
 
-fn collect_activity_data(repo: &Repository) -> Result<(HashMap<String, u32>, HashMap<u8, u32>)> {
+fn heatmap_ramp_color(level: usize, color: HeatmapColor) -> (u8, u8, u8) {
+    match color {
+        HeatmapColor::Green => [(22, 27, 34), (14, 68, 41), (0, 109, 50), (38, 166, 65), (57, 211, 83)][level],
+        HeatmapColor::Red => [(22, 27, 34), (88, 20, 20), (140, 30, 30), (191, 40, 40), (230, 50, 50)][level],
+    }
+}
+
+fn heatmap_intensity_level(count: u32, max: u32) -> usize {
+    if count == 0 || max == 0 {
+        return 0;
+    }
+
+    let ratio = count as f64 / max as f64;
+    if ratio > 0.75 {
+        4
+    } else if ratio > 0.5 {
+        3
+    } else if ratio > 0.25 {
+        2
+    } else {
+        1
+    }
+}
+
+fn display_commit_heatmap(daily_commits: &std::collections::BTreeMap<chrono::NaiveDate, u32>, since: DateTime<Utc>, until: DateTime<Utc>, color: HeatmapColor) {
+    let start = since.date_naive();
+    let end = until.date_naive();
+
+    let first_monday = start - chrono::Duration::days(start.weekday().num_days_from_monday() as i64);
+    let total_days = (end - first_monday).num_days() + 1;
+    let num_weeks = ((total_days as f64) / 7.0).ceil() as usize;
+
+    let mut grid = vec![vec![0u32; num_weeks]; 7];
+    for (date, count) in daily_commits {
+        if *date < first_monday || *date > end {
+            continue;
+        }
+        let week = ((*date - first_monday).num_days() / 7) as usize;
+        let weekday = date.weekday().num_days_from_monday() as usize;
+        if week < num_weeks {
+            grid[weekday][week] = *count;
+        }
+    }
+
+    let max_count = grid.iter().flatten().max().copied().unwrap_or(0);
+
+    // Month labels along the top, each printed over the first week that starts
+    // in that month; a label spans two of the 2-character week columns.
+    print!("    ");
+    let mut last_month = None;
+    let mut week = 0;
+    while week < num_weeks {
+        let week_start = first_monday + chrono::Duration::days((week * 7) as i64);
+        let month = week_start.format("%m").to_string();
+        if Some(&month) != last_month.as_ref() && week_start.day() <= 7 {
+            print!("{:<4}", week_start.format("%b"));
+            last_month = Some(month);
+            week += 2;
+        } else {
+            print!("  ");
+            week += 1;
+        }
+    }
+    println!();
+
+    let day_labels = ["Mon", "   ", "Wed", "   ", "Fri", "   ", "   "];
+    for (weekday, label) in day_labels.iter().enumerate() {
+        print!("{} ", label);
+        for &count in &grid[weekday] {
+            let level = heatmap_intensity_level(count, max_count);
+            let (r, g, b) = heatmap_ramp_color(level, color);
+            print!("\x1b[38;2;{};{};{}m■ \x1b[0m", r, g, b);
+        }
+        println!();
+    }
+
+    print!("\nLess ");
+    for level in 0..5 {
+        let (r, g, b) = heatmap_ramp_color(level, color);
+        print!("\x1b[38;2;{};{};{}m■ \x1b[0m", r, g, b);
+    }
+    println!("More");
+}
+
+type ActivityMaps = (HashMap<String, u32>, HashMap<u8, u32>, std::collections::BTreeMap<chrono::NaiveDate, u32>);
+
+fn collect_activity_data(repo_paths: &[PathBuf], opts: &RunOptions) -> Result<ActivityMaps> {
     let mut monthly_commits: HashMap<String, u32> = HashMap::new();
     let mut hourly_commits: HashMap<u8, u32> = HashMap::new();
-    
-    let mut revwalk = repo.revwalk()?;
-    revwalk.push_head()?;
-    
-    for oid in revwalk {
-        let oid = oid?;
-        let commit = repo.find_commit(oid)?;
-        let author = commit.author();
-        let commit_time = DateTime::from_timestamp(author.when().seconds(), 0)
-            .unwrap_or_default()
-            .with_timezone(&Utc);
-        
-        let month_key = commit_time.format("%Y-%m").to_string();
-        *monthly_commits.entry(month_key).or_insert(0) += 1;
-        
-        let hour = commit_time.hour() as u8;
-        *hourly_commits.entry(hour).or_insert(0) += 1;
+    let mut daily_commits: std::collections::BTreeMap<chrono::NaiveDate, u32> = std::collections::BTreeMap::new();
+
+    let pool = build_thread_pool(opts.jobs)?;
+    let (since, until) = (opts.since, opts.until);
+
+    for repo_path in repo_paths {
+        let repo = Repository::open(repo_path)?;
+        let mut revwalk = repo.revwalk()?;
+        push_refs_for_analysis(&repo, &mut revwalk, opts.branches, opts.all_branches)?;
+        let oids = revwalk.collect::<std::result::Result<Vec<_>, _>>()?;
+        let chunk_len = chunk_size(oids.len(), pool.current_num_threads());
+
+        let partials: Vec<ActivityMaps> = pool.install(|| {
+            oids.par_chunks(chunk_len)
+                .map(|chunk| -> Result<ActivityMaps> {
+                    let repo = Repository::open(repo_path)?;
+                    let mut local_monthly: HashMap<String, u32> = HashMap::new();
+                    let mut local_hourly: HashMap<u8, u32> = HashMap::new();
+                    let mut local_daily: std::collections::BTreeMap<chrono::NaiveDate, u32> = std::collections::BTreeMap::new();
+
+                    for &oid in chunk {
+                        let commit = repo.find_commit(oid)?;
+                        let author = commit.author();
+                        let commit_time = DateTime::from_timestamp(author.when().seconds(), 0)
+                            .unwrap_or_default()
+                            .with_timezone(&Utc);
+
+                        if commit_time < since || commit_time > until {
+                            continue;
+                        }
+
+                        let month_key = commit_time.format("%Y-%m").to_string();
+                        *local_monthly.entry(month_key).or_insert(0) += 1;
+
+                        let hour = commit_time.hour() as u8;
+                        *local_hourly.entry(hour).or_insert(0) += 1;
+
+                        *local_daily.entry(commit_time.date_naive()).or_insert(0) += 1;
+                    }
+
+                    Ok((local_monthly, local_hourly, local_daily))
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        for (local_monthly, local_hourly, local_daily) in partials {
+            for (key, count) in local_monthly {
+                *monthly_commits.entry(key).or_insert(0) += count;
+            }
+            for (key, count) in local_hourly {
+                *hourly_commits.entry(key).or_insert(0) += count;
+            }
+            for (key, count) in local_daily {
+                *daily_commits.entry(key).or_insert(0) += count;
+            }
+        }
     }
-    
-    Ok((monthly_commits, hourly_commits))
+
+    Ok((monthly_commits, hourly_commits, daily_commits))
 }
 
-fn analyze_files(repo_path: &PathBuf, json_output: bool) -> Result<()> {
-    let repo = Repository::open(repo_path)?;
-    let sorted_files = collect_file_data(&repo)?;
+fn analyze_files(repo_paths: &[PathBuf], json_output: bool, opts: &RunOptions, sort: FileSortBy) -> Result<()> {
+    let sorted_files = collect_file_data(repo_paths, opts, sort)?;
 
     if json_output {
         println!("{}", serde_json::to_string_pretty(&sorted_files)?);
     } else {
         println!("📁 Most Modified Files:");
-        println!("{:<50} {:<8} {:<20}", "File Path", "Commits", "Last Modified");
-        println!("{}", "=".repeat(80));
-        
+        println!("{:<50} {:<8} {:<8} {:<8} {:<20}", "File Path", "Commits", "+Lines", "-Lines", "Last Modified");
+        println!("{}", "=".repeat(96));
+
         for file in sorted_files.iter().take(20) {
-            println!("{:<50} {:<8} {:<20}", 
+            println!("{:<50} {:<8} {:<8} {:<8} {:<20}",
                 file.path,
                 file.commits,
+                file.lines_added,
+                file.lines_removed,
                 file.last_modified.format("%Y-%m-%d %H:%M")
             );
         }
@@ -458,62 +893,201 @@ fn analyze_files(repo_path: &PathBuf, json_output: bool) -> Result<()> {
     Ok(())
 }
 
-fn collect_file_data(repo: &Repository) -> Result<Vec<FileStats>> {
+/// Merges a chunk-local file-stats aggregate into the shared map.
+fn merge_file_stats(file_stats: &mut HashMap<String, FileStats>, path: String, incoming: FileStats) {
+    file_stats.entry(path)
+        .and_modify(|stats| {
+            stats.commits += incoming.commits;
+            stats.lines_added += incoming.lines_added;
+            stats.lines_removed += incoming.lines_removed;
+            if incoming.last_modified > stats.last_modified {
+                stats.last_modified = incoming.last_modified;
+            }
+        })
+        .or_insert(incoming);
+}
+
+fn collect_file_data(repo_paths: &[PathBuf], opts: &RunOptions, sort: FileSortBy) -> Result<Vec<FileStats>> {
     let mut file_stats: HashMap<String, FileStats> = HashMap::new();
-    
-    let mut revwalk = repo.revwalk()?;
-    revwalk.push_head()?;
-    
-    for oid in revwalk {
-        let oid = oid?;
-        let commit = repo.find_commit(oid)?;
-        let tree = commit.tree()?;
-        let author = commit.author();
-        let commit_time = DateTime::from_timestamp(author.when().seconds(), 0)
-            .unwrap_or_default()
-            .with_timezone(&Utc);
-        
-        if let Some(parent) = commit.parents().next() {
-            let parent_tree = parent.tree()?;
-            let diff = repo.diff_tree_to_tree(Some(&parent_tree), Some(&tree), None)?;
-            
-            diff.foreach(
-                &mut |delta, _progress| {
-                    if let Some(new_file) = delta.new_file().path() {
-                        let path_str = new_file.to_string_lossy().to_string();
-                        
-                        file_stats.entry(path_str.clone())
-                            .and_modify(|stats| {
-                                stats.commits += 1;
-                                if commit_time > stats.last_modified {
-                                    stats.last_modified = commit_time;
+
+    let pool = build_thread_pool(opts.jobs)?;
+    let (since, until) = (opts.since, opts.until);
+
+    for repo_path in repo_paths {
+        let repo = Repository::open(repo_path)?;
+        let repo_name = repo_display_name(repo_path);
+
+        let mut revwalk = repo.revwalk()?;
+        push_refs_for_analysis(&repo, &mut revwalk, opts.branches, opts.all_branches)?;
+        let oids = revwalk.collect::<std::result::Result<Vec<_>, _>>()?;
+        let chunk_len = chunk_size(oids.len(), pool.current_num_threads());
+
+        let partials: Vec<HashMap<String, FileStats>> = pool.install(|| {
+            oids.par_chunks(chunk_len)
+                .map(|chunk| -> Result<HashMap<String, FileStats>> {
+                    let repo = Repository::open(repo_path)?;
+                    let mut local: HashMap<String, FileStats> = HashMap::new();
+
+                    for &oid in chunk {
+                        let commit = repo.find_commit(oid)?;
+                        let tree = commit.tree()?;
+                        let author = commit.author();
+                        let commit_time = DateTime::from_timestamp(author.when().seconds(), 0)
+                            .unwrap_or_default()
+                            .with_timezone(&Utc);
+
+                        if commit_time < since || commit_time > until {
+                            continue;
+                        }
+
+                        if let Some(parent) = commit.parents().next() {
+                            let parent_tree = parent.tree()?;
+                            let diff = repo.diff_tree_to_tree(Some(&parent_tree), Some(&tree), None)?;
+
+                            for (idx, delta) in diff.deltas().enumerate() {
+                                if let Some(new_file) = delta.new_file().path() {
+                                    let path_str = format!("{}/{}", repo_name, new_file.to_string_lossy());
+                                    let (additions, deletions) = match Patch::from_diff(&diff, idx)? {
+                                        Some(patch) => {
+                                            let (_, additions, deletions) = patch.line_stats()?;
+                                            (additions as u32, deletions as u32)
+                                        },
+                                        None => (0, 0),
+                                    };
+
+                                    local.entry(path_str.clone())
+                                        .and_modify(|stats| {
+                                            stats.commits += 1;
+                                            stats.lines_added += additions;
+                                            stats.lines_removed += deletions;
+                                            if commit_time > stats.last_modified {
+                                                stats.last_modified = commit_time;
+                                            }
+                                        })
+                                        .or_insert(FileStats {
+                                            path: path_str,
+                                            repo: repo_name.clone(),
+                                            commits: 1,
+                                            last_modified: commit_time,
+                                            lines_added: additions,
+                                            lines_removed: deletions,
+                                        });
                                 }
-                            })
-                            .or_insert(FileStats {
-                                path: path_str,
-                                commits: 1,
-                                last_modified: commit_time,
-                            });
+                            }
+                        }
                     }
-                    true
-                },
-                None,
-                None,
-                None,
-            )?;
+
+                    Ok(local)
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        for partial in partials {
+            for (path, incoming) in partial {
+                merge_file_stats(&mut file_stats, path, incoming);
+            }
         }
     }
-    
+
     let mut sorted_files: Vec<_> = file_stats.into_values().collect();
-    sorted_files.sort_by(|a, b| b.commits.cmp(&a.commits));
-    
+    match sort {
+        FileSortBy::Commits => sorted_files.sort_by(|a, b| b.commits.cmp(&a.commits)),
+        FileSortBy::Churn => sorted_files.sort_by(|a, b| {
+            (b.lines_added + b.lines_removed).cmp(&(a.lines_added + a.lines_removed))
+        }),
+    }
+
+
     Ok(sorted_files)
 }
 
-fn analyze_all(repo_path: &PathBuf, json_output: bool) -> Result<()> {
+fn analyze_hours(repo_path: &PathBuf, json_output: bool, mailmap_path: &Option<PathBuf>) -> Result<()> {
+    let repo = Repository::open(repo_path)?;
+    let mailmap = load_mailmap(&repo, mailmap_path)?;
+    let hours = collect_hours_data(&repo, &mailmap)?;
+
+    let total_hours: f64 = hours.iter().map(|h| h.hours).sum();
+    let total_estimated_days = total_hours / HOURS_PER_WORKDAY;
+
+    if json_output {
+        let report = serde_json::json!({
+            "contributors": hours,
+            "total_hours": total_hours,
+            "total_estimated_days": total_estimated_days,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("⏱️  Estimated Developer Time Invested:");
+        println!("{:<25} {:<25} {:<8} {:<10} {:<12}", "Name", "Email", "Commits", "Hours", "Workdays");
+        println!("{}", "=".repeat(90));
+
+        for contributor in hours.iter().take(10) {
+            println!("{:<25} {:<25} {:<8} {:<10.1} {:<12.1}",
+                contributor.name,
+                contributor.email,
+                contributor.commits,
+                contributor.hours,
+                contributor.estimated_days
+            );
+        }
+
+        println!("\nTotal: {:.1} hours (~{:.1} workdays)", total_hours, total_estimated_days);
+    }
+
+    Ok(())
+}
+
+fn collect_hours_data(repo: &Repository, mailmap: &Mailmap) -> Result<Vec<ContributorHours>> {
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push_head()?;
+    let oids = revwalk.collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let contributors = collect_author_times(repo, mailmap, &oids, None)?;
+
+    let mut hours: Vec<ContributorHours> = contributors
+        .into_values()
+        .map(|(name, email, times)| {
+            let commits = times.len() as u32;
+            let total_minutes = estimate_session_minutes(&times);
+            let hours = total_minutes as f64 / 60.0;
+
+            ContributorHours {
+                name,
+                email,
+                commits,
+                hours,
+                estimated_days: hours / HOURS_PER_WORKDAY,
+            }
+        })
+        .collect();
+
+    hours.sort_by(|a, b| b.hours.partial_cmp(&a.hours).unwrap_or(std::cmp::Ordering::Equal));
+
+    Ok(hours)
+}
+
+fn estimate_session_minutes(commit_times: &[DateTime<Utc>]) -> i64 {
+    let mut sorted_times = commit_times.to_vec();
+    sorted_times.sort();
+
+    let mut total_minutes = FIRST_COMMIT_ADDITION_MINUTES;
+
+    for pair in sorted_times.windows(2) {
+        let gap_minutes = (pair[1] - pair[0]).num_minutes();
+        if gap_minutes < MAX_COMMIT_DIFFERENCE_MINUTES {
+            total_minutes += gap_minutes;
+        } else {
+            total_minutes += FIRST_COMMIT_ADDITION_MINUTES;
+        }
+    }
+
+    total_minutes
+}
+
+fn analyze_all(repo_paths: &[PathBuf], json_output: bool, opts: &RunOptions) -> Result<()> {
     println!("🔍 Running all analyses...");
-    analyze_contributors(repo_path, json_output)?;
-    analyze_activity(repo_path, json_output)?;
-    analyze_files(repo_path, json_output)?;
+    analyze_contributors(repo_paths, json_output, opts)?;
+    analyze_activity(repo_paths, json_output, opts, false, HeatmapColor::Green)?;
+    analyze_files(repo_paths, json_output, opts, FileSortBy::Commits)?;
     Ok(())
 }